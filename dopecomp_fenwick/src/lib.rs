@@ -17,15 +17,20 @@
 /// array (here, by last element, I mean the last two elements if we were to trim the array `v` to
 /// have the length 6).
 ///
-/// One thing to note is that everything in here is 1-indexed. When using [[FenwickTree<T>::from_data]], 
+/// `FenwickTree<M>` is generic over the combining operation via the [Monoid] `M`, rather than
+/// being hard-wired to addition: `M::identity()` seeds queries and `M::append` combines nodes, so
+/// the same tree works for min/max, XOR, or any other associative operation by swapping `M`. The
+/// common "sum" case is covered out of the box by [AddMonoid].
+///
+/// One thing to note is that everything in here is 1-indexed. When using [FenwickTree::from_data],
 /// you should make sure that the vectors have length (1 + n) instead of n.
 ///
 /// ## Panics
 ///
 /// Every operation will panic when accessed out of bounds (including 0). Also panics if the given
 /// operations as parameters panic themselves.
-pub struct FenwickTree<T> {
-    pub data: Vec<T>,
+pub struct FenwickTree<M: Monoid> {
+    pub data: Vec<M::T>,
 }
 
 #[inline]
@@ -33,17 +38,75 @@ fn lsb(val: usize) -> usize {
     val & (!val + 1)
 }
 
-impl<T> FenwickTree<T> {
-    /// Create an empty Fenwick Tree with default values.
-    pub fn new(n: usize) -> FenwickTree<T> 
-    where T: Default {
+/// A value type closed under an associative combining operation.
+pub trait Semigroup {
+    /// The value type this semigroup combines.
+    type T;
+
+    /// Combine two values. Must be associative: `append(&append(a, b), c) == append(a,
+    /// &append(b, c))`.
+    fn append(a: &Self::T, b: &Self::T) -> Self::T;
+}
+
+/// A [Semigroup] with a two-sided identity element.
+pub trait Monoid: Semigroup {
+    /// The identity element: `append(&identity(), x) == append(x, &identity()) == *x`.
+    fn identity() -> Self::T;
+}
+
+/// A [Monoid] where every element has an inverse, so a combination can be undone.
+///
+/// This is what lets [FenwickTree::range] answer range queries: `range(l, r)` is computed as
+/// `append(prefix(r), invert(prefix(l - 1)))`, i.e. "everything up to `r`, minus everything before
+/// `l`".
+pub trait Group: Monoid {
+    /// The inverse of `x`: `append(x, &invert(x)) == identity()`.
+    fn invert(x: &Self::T) -> Self::T;
+}
+
+use std::marker::PhantomData;
+use std::ops::{Add, Bound, Neg, RangeBounds};
+
+/// [Monoid] marker for the common "sum" case: `append` is `+`, `identity` is `0`. Also a [Group]
+/// when `T` supports negation, so [FenwickTree::range] is available for free.
+///
+/// This is a marker type, not a value: it only ever appears as a type parameter, e.g.
+/// `FenwickTree::<AddMonoid<i64>>::new(n)`.
+pub struct AddMonoid<T>(PhantomData<T>);
+
+impl<T> Semigroup for AddMonoid<T>
+where T: Copy + Add<Output = T> {
+    type T = T;
+
+    fn append(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+
+impl<T> Monoid for AddMonoid<T>
+where T: Copy + Add<Output = T> + Default {
+    fn identity() -> T {
+        T::default()
+    }
+}
+
+impl<T> Group for AddMonoid<T>
+where T: Copy + Add<Output = T> + Default + Neg<Output = T> {
+    fn invert(x: &T) -> T {
+        -*x
+    }
+}
+
+impl<M: Monoid> FenwickTree<M> {
+    /// Create an empty Fenwick Tree seeded with `M::identity()`.
+    pub fn new(n: usize) -> FenwickTree<M> {
         FenwickTree {
-            data: (0..n + 1).map(|_| { T::default() }).collect()
+            data: (0..n + 1).map(|_| M::identity()).collect()
         }
     }
 
     /// Create a Fenwick Tree from its underlying data.
-    pub fn from_data(data: Vec<T>)  -> FenwickTree<T>{
+    pub fn from_data(data: Vec<M::T>) -> FenwickTree<M> {
         FenwickTree {
             data
         }
@@ -51,29 +114,37 @@ impl<T> FenwickTree<T> {
 
     /// Update a Fenwick Tree at the given position.
     ///
-    /// `update` is a function that receives the Fenwick Tree mutable reference, so it applies the
-    /// update on that node.
+    /// This is a lower-level escape hatch that bypasses `M::append`, for the rare case where the
+    /// update isn't expressible as "combine with a value" (e.g. overwriting a node outright).
+    /// Prefer [FenwickTree::point_update] when `M::append` already does what you want.
     pub fn update<F>(&mut self, mut pos: usize, update: F)
-    where F: Fn(&mut T) {
+    where F: Fn(&mut M::T) {
         if pos == 0 || pos >= self.data.len() {
             panic!("Update happens outside of Fenwick Tree bounds: {}, length is {}.", pos, self.data.len())
         }
-    
+
         while pos < self.data.len() {
             update(&mut self.data[pos]);
             pos += lsb(pos);
         }
     }
 
+    /// Combine `value` into position `pos` via `M::append`.
+    pub fn point_update(&mut self, pos: usize, value: &M::T) {
+        self.update(pos, |node| { *node = M::append(node, value); });
+    }
+
     /// Query the Fenwick Tree at a given position.
     ///
-    /// `neutral` is the neutral element of the ring on which the Fenwick Tree works. For instance,
-    /// when doing sums over ranges, `neutral` should be 0.
+    /// This is a lower-level escape hatch that bypasses `M::identity`/`M::append`, letting callers
+    /// thread through a `neutral` element and `composition` function of their own. Prefer
+    /// [FenwickTree::prefix] when `M` already describes the operation you want.
     ///
-    /// `composition` should combine the resultant type with a node from the Fenwick Tree and
-    /// return a new number, that is the "sum" of the two.
+    /// `neutral` is the neutral element of the operation the query combines with. `composition`
+    /// should combine the resultant type with a node from the Fenwick Tree and return a new value,
+    /// that is the "sum" of the two.
     pub fn query<Q, F>(&self, mut pos: usize, neutral: Q, composition: F) -> Q
-    where F: Fn(Q, &T) -> Q,
+    where F: Fn(Q, &M::T) -> Q,
           Q: Copy {
         let mut res = neutral;
 
@@ -89,22 +160,26 @@ impl<T> FenwickTree<T> {
         res
     }
 
+    /// The `M`-combination of every element in `1..=pos`.
+    pub fn prefix(&self, pos: usize) -> M::T
+    where M::T: Copy {
+        self.query(pos, M::identity(), |acc, node| M::append(&acc, node))
+    }
+
     /// Binary searches a property on the Fenwick Tree.
     ///
-    /// `neutral` is the neutral element of the ring on which the Fenwick Tree works. For instance,
-    /// when doing sums over ranges, `neutral` should be 0.
-    ///
-    /// `composition` should combine the resultant type with a node from the Fenwick Tree and
-    /// return a new number, that is the "sum" of the two.
+    /// `neutral` is the neutral element of the operation the search combines with. `composition`
+    /// should combine the resultant type with a node from the Fenwick Tree and return a new value,
+    /// that is the "sum" of the two.
     ///
     /// `eval` should be an evaluation function that returns `true` if the given value is too
     /// small, or `false` if it is too large. Therefore, this function will return a pair (x, y) where `x`
     /// is the largest position such that `eval(query(x)) = true` and `y` is the lowest number such that
-    /// `eval(query(y)) = false`. In particular, `y = x + 1`. This works on the assumption that `eval(query(0)) = true` 
+    /// `eval(query(y)) = false`. In particular, `y = x + 1`. This works on the assumption that `eval(query(0)) = true`
     /// and `eval(query(n + 1)) = false`.
     pub fn bin_search<F, E, Q>(&self, eval: E, neutral: Q, composition: F) -> (usize, usize)
     where E: Fn(Q) -> bool,
-          F: Fn(Q, &T) -> Q,
+          F: Fn(Q, &M::T) -> Q,
           Q: Copy {
         let mut pos = 0;
         let mut sum = neutral;
@@ -125,75 +200,233 @@ impl<T> FenwickTree<T> {
     }
 }
 
-use std::ops::{Add, Sub};
+impl<M: Group> FenwickTree<M> {
+    /// The `M`-combination of every element in `start..=end` (both ends inclusive), computed as
+    /// `append(prefix(end), invert(prefix(start - 1)))`.
+    pub fn range(&self, start: usize, end: usize) -> M::T
+    where M::T: Copy {
+        M::append(&self.prefix(end), &M::invert(&self.prefix(start - 1)))
+    }
 
-impl<T> FenwickTree<T>
-where T: Copy + Default + Add<Output = T> + Sub<Output = T> {
-    /// Add a value to a position in the Fenwick Tree.
-    pub fn add_value(&mut self, pos: usize, val: T) {
-        self.update(pos, |e| { *e = *e + val; });
+    /// The `M`-combination over an arbitrary [RangeBounds], accepting `a..b`, `a..=b`, `..b`,
+    /// `a..`, and `..` so callers don't have to hand-decrement a start bound (which panics at the
+    /// left edge, since positions are 1-indexed).
+    pub fn sum<R: RangeBounds<usize>>(&self, range: R) -> M::T
+    where M::T: Copy {
+        let l = match range.start_bound() {
+            Bound::Included(&l) => l,
+            Bound::Excluded(&l) => l + 1,
+            Bound::Unbounded => 0,
+        };
+        let r = match range.end_bound() {
+            Bound::Included(&r) => r + 1,
+            Bound::Excluded(&r) => r,
+            Bound::Unbounded => self.data.len(),
+        };
+
+        if l == 0 {
+            self.prefix(r - 1)
+        } else {
+            M::append(&self.prefix(r - 1), &M::invert(&self.prefix(l - 1)))
+        }
     }
+}
+
+impl<M: Monoid> FenwickTree<M>
+where M::T: Copy + Ord {
+    /// The smallest index `x` with `prefix(x) >= s`, found via O(log n) BIT descent rather than
+    /// reusing the generic [FenwickTree::bin_search] with closures.
+    ///
+    /// Debug-asserts that the total `prefix` reaches `s`, since an unreachable target would
+    /// otherwise silently come back as `data.len()`, one past the last index, instead of
+    /// signalling a caller mistake.
+    pub fn lower_bound(&self, s: M::T) -> usize {
+        debug_assert!(self.prefix(self.data.len() - 1) >= s, "target is unreachable: total prefix never reaches s");
 
-    /// Find the prefix sum of the Fenwick Tree at a position.
-    pub fn prefix_sum(&self, pos: usize) -> T {
-        self.query(pos, T::default(), |s, e| { s + *e })
+        self.descend(|w| w < s)
     }
 
-    /// Find the range sum in the Fenwick Tree at two given positions.
+    /// The smallest index `x` with `prefix(x) > s`.
     ///
-    /// The query range takes both the `start` and the `end`. In particular, `range_sum(b, e) =
-    /// sum(b..=e)`.
-    pub fn range_sum(&self, start: usize, end: usize) -> T {
-        self.query(end, T::default(), |s, e| { s + *e }) -
-            self.query(start - 1, T::default(), |s, e| { s + *e })
+    /// Debug-asserts that the total `prefix` exceeds `s`, for the same reason as
+    /// [FenwickTree::lower_bound].
+    pub fn upper_bound(&self, s: M::T) -> usize {
+        debug_assert!(self.prefix(self.data.len() - 1) > s, "target is unreachable: total prefix never exceeds s");
+
+        self.descend(|w| w <= s)
     }
 
-    /// Binary search on the prefix sums of the Fenwick Tree.
-    pub fn bin_search_sum<E>(&self, eval: E) -> (usize, usize)
-    where E: Fn(T) -> bool {
-        self.bin_search(eval, T::default(), |s, e| { s + *e })
+    /// Shared BIT descent for [FenwickTree::lower_bound]/[FenwickTree::upper_bound]: `accept(w)`
+    /// decides whether `w`, combined with the candidate node, is still too small to stop at.
+    fn descend<F>(&self, accept: F) -> usize
+    where F: Fn(M::T) -> bool {
+        let mut k = 1;
+        while k * 2 < self.data.len() {
+            k *= 2;
+        }
+
+        let mut x = 0;
+        let mut w = M::identity();
+
+        while k > 0 {
+            if x + k < self.data.len() {
+                let candidate = M::append(&w, &self.data[x + k]);
+
+                if accept(candidate) {
+                    w = candidate;
+                    x += k;
+                }
+            }
+
+            k /= 2;
+        }
+
+        x + 1
     }
 }
 
-impl<T> FenwickTree<FenwickTree<T>> {
-    /// Update a 2d Fenwick Tree at the coordinates (x, y).
-    pub fn update_2d<F>(&mut self, x: usize, y: usize, update: F)
-    where F: Fn(&mut T) {
-        self.update(x, |inner| {
-            inner.update(y, &update);
-        });
+/// A 2D Fenwick Tree, supporting point updates and prefix-rectangle queries over a [Monoid].
+///
+/// Unlike [FenwickTree], this doesn't nest `FenwickTree<FenwickTree<M>>`: a monoid's `identity()`
+/// takes no size parameter, so there's no way for an inner tree to know how many columns to
+/// allocate. Instead the two Fenwick dimensions are held in a single `Vec<Vec<M::T>>` grid.
+pub struct FenwickTree2D<M: Monoid> {
+    data: Vec<Vec<M::T>>,
+}
+
+impl<M: Monoid> FenwickTree2D<M> {
+    /// Create an empty `rows` by `cols` 2D Fenwick Tree seeded with `M::identity()`.
+    pub fn new(rows: usize, cols: usize) -> FenwickTree2D<M> {
+        FenwickTree2D {
+            data: (0..rows + 1).map(|_| (0..cols + 1).map(|_| M::identity()).collect()).collect(),
+        }
     }
 
-    /// Query the sum of the rectangle `(1, 1)` to `(x, y)`.
-    pub fn query_2d<Q, F>(&self, x: usize, y: usize, neutral: Q, composition: F) -> Q
-    where F: Fn(Q, &T) -> Q,
-          Q: Copy {
-        
-        self.query(x, neutral, |sum, inner| {
-            inner.query(y, sum, &composition)
-        })
+    /// Combine `value` into the coordinates `(x, y)` via `M::append`.
+    pub fn point_update(&mut self, mut x: usize, y: usize, value: &M::T) {
+        if x == 0 || x >= self.data.len() {
+            panic!("Update happens outside of Fenwick Tree bounds: {}, length is {}.", x, self.data.len())
+        }
+
+        while x < self.data.len() {
+            let row = &mut self.data[x];
+
+            if y == 0 || y >= row.len() {
+                panic!("Update happens outside of Fenwick Tree bounds: {}, length is {}.", y, row.len())
+            }
+
+            let mut yy = y;
+            while yy < row.len() {
+                row[yy] = M::append(&row[yy], value);
+                yy += lsb(yy);
+            }
+
+            x += lsb(x);
+        }
+    }
+
+    /// The `M`-combination of the rectangle from `(1, 1)` to `(x, y)`.
+    pub fn prefix(&self, x: usize, y: usize) -> M::T {
+        let mut res = M::identity();
+
+        if x >= self.data.len() {
+            panic!("Query on Fenwick Tree outside bounds: {}", x);
+        }
+
+        let mut xx = x;
+        while xx > 0 {
+            let row = &self.data[xx];
+
+            if y >= row.len() {
+                panic!("Query on Fenwick Tree outside bounds: {}", y);
+            }
+
+            let mut yy = y;
+            while yy > 0 {
+                res = M::append(&res, &row[yy]);
+                yy -= lsb(yy);
+            }
+
+            xx -= lsb(xx);
+        }
+
+        res
+    }
+}
+
+impl<M: Group> FenwickTree2D<M> {
+    /// The `M`-combination of the rectangle with top-left corner `(x1, y1)` and bottom-right
+    /// corner `(x2, y2)` (all inclusive), via inclusion-exclusion over four prefix rectangles.
+    pub fn range(&self, x1: usize, y1: usize, x2: usize, y2: usize) -> M::T {
+        M::append(
+            &M::append(&self.prefix(x2, y2), &M::invert(&self.prefix(x1 - 1, y2))),
+            &M::invert(&M::append(&self.prefix(x2, y1 - 1), &M::invert(&self.prefix(x1 - 1, y1 - 1)))),
+        )
+    }
+}
+
+/// Counts the number of inversions in `data`: pairs `(i, j)` with `i < j` and `data[i] > data[j]`.
+///
+/// Works for any `Ord` type via coordinate compression onto `1..=n` distinct values, then a single
+/// left-to-right sweep with a Fenwick Tree: for each element, everything already seen with a
+/// strictly greater rank is an inversion with it.
+pub fn count_inversions<T: Ord + Clone>(data: &[T]) -> u64 {
+    let mut sorted: Vec<T> = data.to_vec();
+    sorted.sort();
+    sorted.dedup();
+
+    let mut bit = FenwickTree::<AddMonoid<i64>>::new(sorted.len());
+    let mut inversions = 0u64;
+
+    for (i, value) in data.iter().enumerate() {
+        let rank = sorted.binary_search(value).unwrap() + 1;
+
+        inversions += i as u64 - bit.prefix(rank) as u64;
+        bit.point_update(rank, &1);
     }
-} 
 
-impl<T> FenwickTree<FenwickTree<T>>
-where T: Copy + Default + Add<Output = T> + Sub<Output = T> {
-    /// Add a constant at the given coordinates.
-    pub fn add_value_2d(&mut self, x: usize, y: usize, val: T) {
-        self.update_2d(x, y, |e| { *e = *e + val } );
+    inversions
+}
+
+/// A Fenwick Tree over an arbitrary `Ord` universe via coordinate compression, so callers can ask
+/// "how many values inserted so far fall in this range" without re-deriving ranks themselves.
+pub struct CompressedFenwick<T> {
+    sorted: Vec<T>,
+    bit: FenwickTree<AddMonoid<i64>>,
+}
+
+impl<T: Ord + Clone> CompressedFenwick<T> {
+    /// Build a `CompressedFenwick` over the given universe of values (it need not already be
+    /// sorted or deduplicated).
+    pub fn new(universe: &[T]) -> CompressedFenwick<T> {
+        let mut sorted: Vec<T> = universe.to_vec();
+        sorted.sort();
+        sorted.dedup();
+
+        let bit = FenwickTree::new(sorted.len());
+
+        CompressedFenwick { sorted, bit }
     }
 
-    /// Compute the sum from `(1, 1)` to `(x, y)`
-    pub fn prefix_rectangle_sum(&mut self, x: usize, y: usize) -> T {
-        self.query_2d(x, y, T::default(), |s, e| { s + *e } )
+    fn rank(&self, value: &T) -> usize {
+        self.sorted.binary_search(value)
+            .expect("value outside the CompressedFenwick's universe") + 1
     }
 
-    /// Returns the sum of the rectangle with the top-left corner in `(x1, y1)` and the bottom-right
-    /// corner in `(x2, y2)`
-    pub fn rectangle_sum(&mut self, x1: usize, y1: usize, x2: usize, y2: usize) -> T {
-        self.prefix_rectangle_sum(x2, y2) - 
-            self.prefix_rectangle_sum(x1 - 1, y2) -
-            self.prefix_rectangle_sum(x2, y1 - 1) +
-            self.prefix_rectangle_sum(x1 - 1, y1 - 1)
+    /// Record one more occurrence of `value`.
+    pub fn add(&mut self, value: &T) {
+        let rank = self.rank(value);
+        self.bit.point_update(rank, &1);
+    }
+
+    /// The number of recorded values strictly less than `value`.
+    pub fn count_less(&self, value: &T) -> i64 {
+        self.bit.prefix(self.rank(value) - 1)
+    }
+
+    /// The number of recorded values in `[lo, hi]` (both ends inclusive).
+    pub fn count_range(&self, lo: &T, hi: &T) -> i64 {
+        self.bit.range(self.rank(lo), self.rank(hi))
     }
 }
 
@@ -223,48 +456,48 @@ mod tests {
 
     #[test]
     fn test_addition() {
-        let mut ft = FenwickTree::<i32>::new(5);
+        let mut ft = FenwickTree::<AddMonoid<i32>>::new(5);
 
-        ft.add_value(2, 5);
-        ft.add_value(3, 4);
+        ft.point_update(2, &5);
+        ft.point_update(3, &4);
 
-        assert_eq!(5, ft.prefix_sum(2));
-        assert_eq!(9, ft.prefix_sum(3));
-        assert_eq!(9, ft.prefix_sum(5));
+        assert_eq!(5, ft.prefix(2));
+        assert_eq!(9, ft.prefix(3));
+        assert_eq!(9, ft.prefix(5));
         assert_eq!(vec![0, 0, 5, 4, 9, 0], ft.data);
     }
-    
+
     #[test]
     fn test_additive_large() {
         use rand::rngs::SmallRng;
         use rand::{Rng, SeedableRng};
-        
+
         const LEN: usize = 100;
         const Q: usize = 10000;
 
-        let mut ft = FenwickTree::<i32>::new(LEN);
+        let mut ft = FenwickTree::<AddMonoid<i32>>::new(LEN);
         let mut rng = SmallRng::seed_from_u64(269_696_969);
-        
+
         let mut v = vec![0i32; 1 + LEN];
 
         for _ in 0..Q {
             let t = rng.gen_range(0..=1);
-            
+
             match t {
             0 => {
                 let (pos, val) = (rng.gen_range(1..=LEN), rng.gen_range(-1_000i32..=1_000i32));
                 v[pos] += val;
-                ft.add_value(pos, val);
+                ft.point_update(pos, &val);
             }
             1 => {
                 let (mut a, mut b) = (rng.gen_range(1..=LEN), rng.gen_range(1..=LEN)) ;
-                
+
                 if a > b {
                     std::mem::swap(&mut a, &mut b);
                 }
 
                 let correct_sum = (a..=b).fold(0, |sum, e| { sum + v[e] });
-                let ft_sum = ft.range_sum(a, b);
+                let ft_sum = ft.range(a, b);
 
                 assert_eq!(correct_sum, ft_sum);
             }
@@ -277,41 +510,141 @@ mod tests {
 
     #[test]
     fn test_binary_search() {
-        let mut ft = FenwickTree::<i32>::new(10);
+        let mut ft = FenwickTree::<AddMonoid<i32>>::new(10);
 
         // index:       0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10
         // prefix sums: 0,  0,  3,  4,  6, 12, 16, 16, 20, 20, 25
-        ft.add_value(2,  3);
-        ft.add_value(3,  1);
-        ft.add_value(4,  2);
-        ft.add_value(5,  6);
-        ft.add_value(6,  4);
-        ft.add_value(8,  4);
-        ft.add_value(10, 5);
-
-        assert_eq!((5, 6), ft.bin_search_sum(|val| { val <= 12 }));
-        assert_eq!((0, 1), ft.bin_search_sum(|val| { val <= -1 } ));
-        assert_eq!((10, 11), ft.bin_search_sum(|val| { val <= 26 } ));
-        assert_eq!((7, 8), ft.bin_search_sum(|val| { val <= 16 } ));
+        ft.point_update(2,  &3);
+        ft.point_update(3,  &1);
+        ft.point_update(4,  &2);
+        ft.point_update(5,  &6);
+        ft.point_update(6,  &4);
+        ft.point_update(8,  &4);
+        ft.point_update(10, &5);
+
+        let bin_search_sum = |eval: fn(i32) -> bool| {
+            ft.bin_search(eval, 0, |s, e| { s + *e })
+        };
+
+        assert_eq!((5, 6), bin_search_sum(|val| { val <= 12 }));
+        assert_eq!((0, 1), bin_search_sum(|val| { val <= -1 } ));
+        assert_eq!((10, 11), bin_search_sum(|val| { val <= 26 } ));
+        assert_eq!((7, 8), bin_search_sum(|val| { val <= 16 } ));
     }
 
     #[test]
     fn test_2d_fenwick_tree() {
-        let mut ft = FenwickTree::<FenwickTree<i32>>::from_data(
-            (0..=5).map(|_| { FenwickTree::<i32>::new(5) }).collect()
-        );
-
-        ft.add_value_2d(2, 3, 5);
-        ft.add_value_2d(3, 1, 4);
-        ft.add_value_2d(3, 4, 6);
-        ft.add_value_2d(4, 2, 2);
-        ft.add_value_2d(4, 5, 1);
-        ft.add_value_2d(5, 4, 3);
-
-        assert_eq!(4, ft.prefix_rectangle_sum(4, 1));
-        assert_eq!(5, ft.prefix_rectangle_sum(2, 3));
-        assert_eq!(9, ft.prefix_rectangle_sum(3, 3));
-        assert_eq!(6, ft.prefix_rectangle_sum(5, 2));
-        assert_eq!(18, ft.prefix_rectangle_sum(4, 5));
+        let mut ft = FenwickTree2D::<AddMonoid<i32>>::new(5, 5);
+
+        ft.point_update(2, 3, &5);
+        ft.point_update(3, 1, &4);
+        ft.point_update(3, 4, &6);
+        ft.point_update(4, 2, &2);
+        ft.point_update(4, 5, &1);
+        ft.point_update(5, 4, &3);
+
+        assert_eq!(4, ft.prefix(4, 1));
+        assert_eq!(5, ft.prefix(2, 3));
+        assert_eq!(9, ft.prefix(3, 3));
+        assert_eq!(6, ft.prefix(5, 2));
+        assert_eq!(18, ft.prefix(4, 5));
+    }
+
+    #[test]
+    fn test_2d_fenwick_tree_range() {
+        let mut ft = FenwickTree2D::<AddMonoid<i32>>::new(5, 5);
+
+        ft.point_update(2, 3, &5);
+        ft.point_update(3, 1, &4);
+        ft.point_update(3, 4, &6);
+        ft.point_update(4, 2, &2);
+        ft.point_update(4, 5, &1);
+        ft.point_update(5, 4, &3);
+
+        // Rectangle (3, 2)..=(5, 5) contains the (3, 4), (4, 2), (4, 5) and (5, 4) updates.
+        assert_eq!(6 + 2 + 1 + 3, ft.range(3, 2, 5, 5));
+    }
+
+    #[test]
+    fn test_sum_accepts_every_range_bounds_flavor() {
+        let mut ft = FenwickTree::<AddMonoid<i32>>::new(10);
+
+        // index:       0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10
+        // prefix sums: 0,  0,  3,  4,  6, 12, 16, 16, 20, 20, 25
+        ft.point_update(2,  &3);
+        ft.point_update(3,  &1);
+        ft.point_update(4,  &2);
+        ft.point_update(5,  &6);
+        ft.point_update(6,  &4);
+        ft.point_update(8,  &4);
+        ft.point_update(10, &5);
+
+        assert_eq!(ft.sum(3..6), ft.range(3, 5));
+        assert_eq!(ft.sum(3..=5), ft.range(3, 5));
+        assert_eq!(ft.sum(..6), ft.prefix(5));
+        assert_eq!(ft.sum(3..), ft.range(3, 10));
+        assert_eq!(ft.sum(..), ft.prefix(10));
+        // Starting at position 1 combines `prefix(2)` with the inverse of the empty `prefix(0)`,
+        // which leaves it unchanged.
+        assert_eq!(ft.sum(1..=2), ft.prefix(2));
+    }
+
+    #[test]
+    fn test_lower_and_upper_bound() {
+        let mut ft = FenwickTree::<AddMonoid<i32>>::new(10);
+
+        // index:       0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10
+        // prefix sums: 0,  0,  3,  4,  6, 12, 16, 16, 20, 20, 25
+        ft.point_update(2,  &3);
+        ft.point_update(3,  &1);
+        ft.point_update(4,  &2);
+        ft.point_update(5,  &6);
+        ft.point_update(6,  &4);
+        ft.point_update(8,  &4);
+        ft.point_update(10, &5);
+
+        assert_eq!(5, ft.lower_bound(12));
+        assert_eq!(6, ft.upper_bound(12));
+        assert_eq!(2, ft.lower_bound(1));
+        assert_eq!(10, ft.lower_bound(25));
+        assert_eq!(1, ft.lower_bound(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lower_bound_panics_when_unreachable() {
+        let ft = FenwickTree::<AddMonoid<i32>>::new(5);
+
+        ft.lower_bound(1);
+    }
+
+    #[test]
+    fn test_count_inversions() {
+        assert_eq!(0, count_inversions(&[1, 2, 3, 4]));
+        assert_eq!(10, count_inversions(&[5, 4, 3, 2, 1]));
+        assert_eq!(3, count_inversions(&[2, 4, 1, 3, 5]));
+    }
+
+    #[test]
+    fn test_count_inversions_with_duplicates() {
+        // Only strictly-decreasing pairs count, so repeated values don't add inversions with
+        // themselves, but do with any strictly smaller value that comes after.
+        assert_eq!(2, count_inversions(&[3, 3, 1, 3]));
+    }
+
+    #[test]
+    fn test_compressed_fenwick_count_less_and_range() {
+        let universe = vec![10, 30, 20, 50, 40];
+        let mut cf = CompressedFenwick::new(&universe);
+
+        cf.add(&10);
+        cf.add(&30);
+        cf.add(&30);
+        cf.add(&50);
+
+        assert_eq!(1, cf.count_less(&20));
+        assert_eq!(3, cf.count_less(&40));
+        assert_eq!(2, cf.count_range(&20, &40));
+        assert_eq!(4, cf.count_range(&10, &50));
     }
 }
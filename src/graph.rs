@@ -1,5 +1,8 @@
 //! Graph utilities.
 
+use crate::io::InParser;
+use std::io::Read;
+
 /// Trait for implementing an edge.
 pub trait Edge {
     /// Returns the node that this edge points to.
@@ -161,23 +164,33 @@ where V: Default,
 impl<'a, V, E> EulerianCycleSolver<'a, V, E>
 where V: Default,
       E: Edge {
-    
-    fn eulerian_dfs(&mut self, node: usize) {
-        while self.last_edge[node] < self.graph.adj_list[node].len() {
-            let cnt_edge = self.last_edge[node];
-            let id = self.graph.adj_list[node][cnt_edge];
-            
-            if !self.used_edge[id] {
-                let other = self.graph.edges[id].to(node);
-                self.used_edge[id] = true;
-
-                self.eulerian_dfs(other);
-            }
 
-            self.last_edge[node] += 1; 
-        }
+    /// Hierholzer's algorithm, implemented iteratively with an explicit work stack so graphs with
+    /// hundreds of thousands of edges don't blow the native call stack.
+    ///
+    /// `last_edge[node]` is the "current-arc" pointer: it only ever moves forward, so each edge
+    /// is stepped past at most once per endpoint across the whole traversal.
+    fn eulerian_dfs(&mut self, start_node: usize) {
+        let mut stack = vec![start_node];
 
-        self.result.push(node);
+        while let Some(&node) = stack.last() {
+            if self.last_edge[node] < self.graph.adj_list[node].len() {
+                let cnt_edge = self.last_edge[node];
+                let id = self.graph.adj_list[node][cnt_edge];
+
+                if !self.used_edge[id] {
+                    let other = self.graph.edges[id].to(node);
+                    self.used_edge[id] = true;
+
+                    stack.push(other);
+                } else {
+                    self.last_edge[node] += 1;
+                }
+            } else {
+                self.result.push(node);
+                stack.pop();
+            }
+        }
     }
 }
 
@@ -268,6 +281,608 @@ where V: Default,
     solve_eulerian(graph, graph.undirected, false)
 }
 
+impl<V, E> Graph<V, E>
+where V: Default,
+      E: Edge {
+    /// Build a graph by reading `m` edges as `u v` pairs from an [InParser].
+    ///
+    /// Set `one_indexed` if the input uses 1-based node ids (the common competitive-programming
+    /// convention); they are converted to 0-based before the graph is built. `transf` plays the
+    /// same role as in [from_edges](Graph::from_edges).
+    pub fn read_edge_list<R, F>(
+        parser: &mut InParser<R>,
+        v: usize,
+        m: usize,
+        undirected: bool,
+        one_indexed: bool,
+        transf: F,
+    ) -> Graph<V, E>
+    where R: Read,
+          F: Fn((usize, usize)) -> E {
+        let offset = if one_indexed { 1 } else { 0 };
+
+        let edges: Vec<(usize, usize)> = (0..m)
+            .map(|_| {
+                let a: usize = parser.read();
+                let b: usize = parser.read();
+                (a - offset, b - offset)
+            })
+            .collect();
+
+        Graph::from_edges(v, edges, transf, undirected)
+    }
+
+    /// Build a graph by reading a `v` by `v` adjacency matrix of `0`/`1` entries from an
+    /// [InParser], adding an edge for every nonzero cell.
+    ///
+    /// When `undirected` is `true`, only the upper triangle (`i < j`) is read as edges, since the
+    /// lower triangle is implied by symmetry. `transf` plays the same role as in
+    /// [from_edges](Graph::from_edges).
+    pub fn read_adjacency_matrix<R, F>(
+        parser: &mut InParser<R>,
+        v: usize,
+        undirected: bool,
+        transf: F,
+    ) -> Graph<V, E>
+    where R: Read,
+          F: Fn((usize, usize)) -> E {
+        let mut edges = Vec::new();
+
+        for i in 0..v {
+            for j in 0..v {
+                let cell: u32 = parser.read();
+                if cell != 0 && (!undirected || i < j) {
+                    edges.push((i, j));
+                }
+            }
+        }
+
+        Graph::from_edges(v, edges, transf, undirected)
+    }
+}
+
+/// An edge of a flow network.
+///
+/// Every flow edge is pushed together with a zero-capacity reverse edge (see
+/// [Graph::push_flow_edge]), so `rev` always points at a valid edge id that can be used to undo
+/// or redirect flow along this edge.
+#[derive(Debug, Clone, Copy)]
+pub struct FlowEdge {
+    /// The node this edge points to.
+    pub to: usize,
+
+    /// The total capacity of the edge.
+    pub capacity: i64,
+
+    /// The amount of flow currently pushed through the edge.
+    pub flow: i64,
+
+    /// The per-unit cost of pushing flow through this edge. Always `0` for edges pushed with
+    /// [Graph::push_flow_edge]; the reverse edge of a costed edge carries the negated cost, so
+    /// cancelling flow refunds exactly what was paid to push it.
+    pub cost: i64,
+
+    /// The index in [Graph::edges] of the paired reverse edge.
+    pub rev: usize,
+}
+
+impl FlowEdge {
+    /// Returns how much more flow can be pushed through this edge.
+    pub fn residual(&self) -> i64 {
+        self.capacity - self.flow
+    }
+}
+
+impl Edge for FlowEdge {
+    fn to(&self, _: usize) -> usize { self.to }
+}
+
+impl<V> Graph<V, FlowEdge>
+where V: Default {
+    /// Push a directed flow edge from `from` to `to` with the given capacity.
+    ///
+    /// This also pushes a zero-capacity reverse edge, which is what lets [max_flow] cancel flow
+    /// along this edge. The id of the forward edge is returned, the reverse edge is always
+    /// `id + 1`.
+    pub fn push_flow_edge(&mut self, from: usize, to: usize, capacity: i64) -> usize {
+        self.push_cost_flow_edge(from, to, capacity, 0)
+    }
+
+    /// Push a directed flow edge from `from` to `to` with the given capacity and per-unit cost.
+    ///
+    /// This also pushes a zero-capacity reverse edge carrying the negated cost, which is what
+    /// lets [min_cost_max_flow] cancel flow along this edge without changing its net cost. The id
+    /// of the forward edge is returned, the reverse edge is always `id + 1`.
+    pub fn push_cost_flow_edge(&mut self, from: usize, to: usize, capacity: i64, cost: i64) -> usize {
+        let id = self.edges.len();
+
+        self.push_directed_edge(from, FlowEdge {
+            to,
+            capacity,
+            flow: 0,
+            cost,
+            rev: id + 1,
+        });
+        self.push_directed_edge(to, FlowEdge {
+            to: from,
+            capacity: 0,
+            flow: 0,
+            cost: -cost,
+            rev: id,
+        });
+
+        id
+    }
+}
+
+struct DinicSolver<'a, V>
+where V: Default {
+    graph: &'a mut Graph<V, FlowEdge>,
+    level: Vec<i32>,
+    last_edge: Vec<usize>,
+}
+
+impl<'a, V> DinicSolver<'a, V>
+where V: Default {
+    /// Builds the level graph with a BFS from `src`. Returns `true` if `sink` is reachable.
+    fn bfs(&mut self, src: usize, sink: usize) -> bool {
+        self.level = vec![-1; self.graph.v()];
+        self.level[src] = 0;
+
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back(src);
+
+        while let Some(node) = queue.pop_front() {
+            for &id in &self.graph.adj_list[node] {
+                let edge = &self.graph.edges[id];
+                if edge.residual() > 0 && self.level[edge.to] == -1 {
+                    self.level[edge.to] = self.level[node] + 1;
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        self.level[sink] != -1
+    }
+
+    /// Pushes a blocking flow along the level graph, bounded by `bottleneck`.
+    fn dfs(&mut self, node: usize, sink: usize, bottleneck: i64) -> i64 {
+        if node == sink || bottleneck == 0 {
+            return bottleneck;
+        }
+
+        while self.last_edge[node] < self.graph.adj_list[node].len() {
+            let id = self.graph.adj_list[node][self.last_edge[node]];
+            let (to, residual) = {
+                let edge = &self.graph.edges[id];
+                (edge.to, edge.residual())
+            };
+
+            if residual > 0 && self.level[to] == self.level[node] + 1 {
+                let pushed = self.dfs(to, sink, bottleneck.min(residual));
+
+                if pushed > 0 {
+                    self.graph.edges[id].flow += pushed;
+                    let rev = self.graph.edges[id].rev;
+                    self.graph.edges[rev].flow -= pushed;
+                    return pushed;
+                }
+            }
+
+            self.last_edge[node] += 1;
+        }
+
+        0
+    }
+}
+
+/// Compute the maximum flow from `src` to `sink` using Dinic's algorithm.
+///
+/// Runs in O(V²E) in general, O(E√V) on unit-capacity graphs. After this returns, the residual
+/// capacities (`edge.capacity - edge.flow`) of `graph.edges` can be inspected to recover the
+/// min-cut (edges with zero residual capacity reachable from `src` in the final level graph) or
+/// to tell which edges are saturated.
+pub fn max_flow<V>(graph: &mut Graph<V, FlowEdge>, src: usize, sink: usize) -> i64
+where V: Default {
+    max_flow_limited(graph, src, sink, i64::MAX)
+}
+
+/// Compute the maximum flow from `src` to `sink` using Dinic's algorithm, capped at `max_amount`
+/// units, so callers that only need "at most k units" can stop early instead of draining the
+/// whole residual graph.
+pub fn max_flow_limited<V>(
+    graph: &mut Graph<V, FlowEdge>,
+    src: usize,
+    sink: usize,
+    max_amount: i64,
+) -> i64
+where V: Default {
+    let mut solver = DinicSolver {
+        graph,
+        level: Vec::new(),
+        last_edge: Vec::new(),
+    };
+
+    let mut total = 0;
+
+    while total < max_amount && solver.bfs(src, sink) {
+        solver.last_edge = vec![0; solver.graph.v()];
+
+        loop {
+            let pushed = solver.dfs(src, sink, max_amount - total);
+            if pushed == 0 {
+                break;
+            }
+            total += pushed;
+        }
+    }
+
+    total
+}
+
+/// Compute the minimum-cost maximum flow from `src` to `sink`, returning `(flow, cost)`.
+///
+/// This covers assignment/transportation problems with negative edge costs (e.g. "reward minus
+/// penalty" arcs), which a plain Dijkstra can't handle directly.
+pub fn min_cost_max_flow<V>(graph: &mut Graph<V, FlowEdge>, src: usize, sink: usize) -> (i64, i64)
+where V: Default {
+    min_cost_flow_limited(graph, src, sink, i64::MAX)
+}
+
+/// Compute the minimum-cost flow from `src` to `sink`, capped at `max_amount` units, returning
+/// `(flow, cost)` where `flow <= max_amount`.
+///
+/// Uses the primal-dual / successive-shortest-path method with Johnson potentials: a single
+/// Bellman-Ford (SPFA) pass from `src` absorbs the initial negative costs into a potential `h[]`,
+/// then every subsequent augmenting path is found with Dijkstra over the reduced cost `w' = cost +
+/// h[u] - h[v]`, which stays non-negative as long as `h` is kept up to date with `h[v] += dist[v]`
+/// after each round — this is the invariant that lets Dijkstra replace Bellman-Ford after the
+/// first pass.
+pub fn min_cost_flow_limited<V>(
+    graph: &mut Graph<V, FlowEdge>,
+    src: usize,
+    sink: usize,
+    max_amount: i64,
+) -> (i64, i64)
+where V: Default {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    let v = graph.v();
+    let mut h = vec![0i64; v];
+
+    {
+        let mut reachable = vec![false; v];
+        reachable[src] = true;
+        h[src] = 0;
+
+        for _ in 0..v {
+            for node in 0..v {
+                if !reachable[node] {
+                    continue;
+                }
+                for &id in &graph.adj_list[node] {
+                    let edge = &graph.edges[id];
+                    if edge.residual() > 0 {
+                        let next = h[node] + edge.cost;
+                        if !reachable[edge.to] || next < h[edge.to] {
+                            h[edge.to] = next;
+                            reachable[edge.to] = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut total_flow = 0i64;
+    let mut total_cost = 0i64;
+
+    loop {
+        if total_flow >= max_amount {
+            break;
+        }
+
+        let mut dist = vec![i64::MAX; v];
+        let mut parent_edge = vec![usize::MAX; v];
+        let mut visited = vec![false; v];
+        let mut heap = BinaryHeap::new();
+
+        dist[src] = 0;
+        heap.push(Reverse((0i64, src)));
+
+        while let Some(Reverse((d, node))) = heap.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+
+            for &id in &graph.adj_list[node] {
+                let edge = &graph.edges[id];
+                if edge.residual() <= 0 {
+                    continue;
+                }
+
+                let reduced_cost = edge.cost + h[node] - h[edge.to];
+                debug_assert!(reduced_cost >= 0, "reduced cost went negative, potentials are stale");
+
+                let next_dist = d + reduced_cost;
+                if next_dist < dist[edge.to] {
+                    dist[edge.to] = next_dist;
+                    parent_edge[edge.to] = id;
+                    heap.push(Reverse((next_dist, edge.to)));
+                }
+            }
+        }
+
+        if dist[sink] == i64::MAX {
+            break;
+        }
+
+        for node in 0..v {
+            if dist[node] != i64::MAX {
+                h[node] += dist[node];
+            }
+        }
+
+        let mut bottleneck = max_amount - total_flow;
+        let mut node = sink;
+        while node != src {
+            let id = parent_edge[node];
+            bottleneck = bottleneck.min(graph.edges[id].residual());
+            node = graph.edges[graph.edges[id].rev].to;
+        }
+
+        let mut path_cost = 0i64;
+        let mut node = sink;
+        while node != src {
+            let id = parent_edge[node];
+            graph.edges[id].flow += bottleneck;
+            let rev = graph.edges[id].rev;
+            graph.edges[rev].flow -= bottleneck;
+            path_cost += graph.edges[id].cost;
+            node = graph.edges[rev].to;
+        }
+
+        total_flow += bottleneck;
+        total_cost += bottleneck * path_cost;
+    }
+
+    (total_flow, total_cost)
+}
+
+struct BridgeSolver<'a, V, E>
+where V: Default,
+      E: Edge {
+    graph: &'a Graph<V, E>,
+    disc: Vec<i32>,
+    low: Vec<i32>,
+    timer: i32,
+    bridges: Vec<usize>,
+    is_articulation: Vec<bool>,
+    edge_stack: Vec<usize>,
+    components: Vec<Vec<usize>>,
+}
+
+/// One stack frame of the iterative Tarjan DFS below, standing in for a single recursive call
+/// `dfs(node, entry_edge)` paused at the point right after dispatching its `index`-th neighbour.
+struct BridgeFrame {
+    node: usize,
+    entry_edge: usize,
+    index: usize,
+    children: usize,
+}
+
+impl<'a, V, E> BridgeSolver<'a, V, E>
+where V: Default,
+      E: Edge {
+    /// `entry_edge` is the id of the edge used to reach `node`, or [usize::MAX] for the DFS root.
+    /// Returns the number of DFS tree children of `node`.
+    ///
+    /// Implemented iteratively with an explicit stack (rather than recursively) so that deep
+    /// chains don't overflow the call stack, matching [EulerianCycleSolver::eulerian_dfs].
+    fn dfs(&mut self, node: usize, entry_edge: usize) -> usize {
+        let mut stack = vec![BridgeFrame { node, entry_edge, index: 0, children: 0 }];
+
+        self.disc[node] = self.timer;
+        self.low[node] = self.timer;
+        self.timer += 1;
+
+        let mut root_children = 0;
+
+        while let Some(top) = stack.len().checked_sub(1) {
+            let node = stack[top].node;
+
+            if stack[top].index >= self.graph.adj_list[node].len() {
+                let frame = stack.pop().unwrap();
+
+                match stack.last() {
+                    Some(parent_frame) => {
+                        let parent = parent_frame.node;
+                        let parent_is_root = parent_frame.entry_edge == usize::MAX;
+                        let child_low = self.low[frame.node];
+
+                        self.low[parent] = self.low[parent].min(child_low);
+
+                        if child_low > self.disc[parent] {
+                            self.bridges.push(frame.entry_edge);
+                        }
+
+                        if !parent_is_root && child_low >= self.disc[parent] {
+                            self.is_articulation[parent] = true;
+                        }
+
+                        if child_low >= self.disc[parent] {
+                            let mut component = Vec::new();
+                            while let Some(edge) = self.edge_stack.pop() {
+                                component.push(edge);
+                                if edge == frame.entry_edge {
+                                    break;
+                                }
+                            }
+                            self.components.push(component);
+                        }
+                    }
+                    None => root_children = frame.children,
+                }
+
+                continue;
+            }
+
+            let id = self.graph.adj_list[node][stack[top].index];
+            stack[top].index += 1;
+
+            if id == stack[top].entry_edge {
+                continue;
+            }
+
+            let to = self.graph.edges[id].to(node);
+
+            if self.disc[to] == -1 {
+                self.disc[to] = self.timer;
+                self.low[to] = self.timer;
+                self.timer += 1;
+
+                self.edge_stack.push(id);
+                stack[top].children += 1;
+
+                stack.push(BridgeFrame { node: to, entry_edge: id, index: 0, children: 0 });
+            } else if self.disc[to] < self.disc[node] {
+                self.edge_stack.push(id);
+                self.low[node] = self.low[node].min(self.disc[to]);
+            }
+        }
+
+        root_children
+    }
+}
+
+/// Find all bridges (cut edges) of an undirected graph, returned as edge ids.
+///
+/// Uses a Tarjan low-link DFS that tracks the *edge id* used to enter each node (rather than the
+/// parent node), so parallel edges between the same pair of nodes are handled correctly.
+pub fn find_bridges<V, E>(graph: &Graph<V, E>) -> Vec<usize>
+where V: Default,
+      E: Edge {
+    run_bridge_solver(graph).bridges
+}
+
+/// Find all articulation points (cut vertices) of an undirected graph, returned as node ids.
+pub fn find_articulation_points<V, E>(graph: &Graph<V, E>) -> Vec<usize>
+where V: Default,
+      E: Edge {
+    run_bridge_solver(graph).is_articulation
+        .into_iter()
+        .enumerate()
+        .filter(|(_, is_art)| *is_art)
+        .map(|(node, _)| node)
+        .collect()
+}
+
+/// Find the biconnected components of an undirected graph, each given as a list of edge ids.
+pub fn biconnected_components<V, E>(graph: &Graph<V, E>) -> Vec<Vec<usize>>
+where V: Default,
+      E: Edge {
+    run_bridge_solver(graph).components
+}
+
+fn run_bridge_solver<'a, V, E>(graph: &'a Graph<V, E>) -> BridgeSolver<'a, V, E>
+where V: Default,
+      E: Edge {
+    let mut solver = BridgeSolver {
+        graph,
+        disc: vec![-1; graph.v()],
+        low: vec![0; graph.v()],
+        timer: 0,
+        bridges: Vec::new(),
+        is_articulation: vec![false; graph.v()],
+        edge_stack: Vec::new(),
+        components: Vec::new(),
+    };
+
+    for node in 0..graph.v() {
+        if solver.disc[node] == -1 {
+            let root_children = solver.dfs(node, usize::MAX);
+            solver.is_articulation[node] = root_children >= 2;
+        }
+    }
+
+    solver
+}
+
+/// Trait for edges that carry a weight, usable for shortest-path algorithms like [dijkstra].
+pub trait WeightedEdge<W>: Edge {
+    /// Returns the weight of this edge.
+    fn weight(&self) -> W;
+}
+
+impl Edge for (usize, i64) {
+    fn to(&self, _: usize) -> usize { self.0 }
+}
+
+impl WeightedEdge<i64> for (usize, i64) {
+    fn weight(&self) -> i64 { self.1 }
+}
+
+impl Edge for (usize, usize, i64) {
+    fn to(&self, from: usize) -> usize {
+        self.0 ^ self.1 ^ from
+    }
+}
+
+impl BidirectionalEdge for (usize, usize, i64) {
+    fn as_pair(&self) -> (usize, usize) {
+        (self.0, self.1)
+    }
+}
+
+impl WeightedEdge<i64> for (usize, usize, i64) {
+    fn weight(&self) -> i64 { self.2 }
+}
+
+/// Compute the shortest distance from `source` to every node using Dijkstra's algorithm.
+///
+/// Returns a `dist` vector (`None` for nodes that are unreachable) and a `parent` vector holding,
+/// for every reachable node other than `source`, the previous node on some shortest path to it
+/// (so callers can walk `parent` backwards to reconstruct the path).
+///
+/// Requires every edge weight to be non-negative; negative weights can make the algorithm return
+/// a distance that isn't actually shortest.
+pub fn dijkstra<V, E, W>(graph: &Graph<V, E>, source: usize) -> (Vec<Option<W>>, Vec<Option<usize>>)
+where V: Default,
+      E: WeightedEdge<W>,
+      W: Ord + Copy + Default + std::ops::Add<Output = W> {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    let mut dist: Vec<Option<W>> = vec![None; graph.v()];
+    let mut parent: Vec<Option<usize>> = vec![None; graph.v()];
+    let mut heap = BinaryHeap::new();
+
+    dist[source] = Some(W::default());
+    heap.push(Reverse((W::default(), source)));
+
+    while let Some(Reverse((d, node))) = heap.pop() {
+        if dist[node].is_some_and(|cur| d > cur) {
+            continue;
+        }
+
+        for &id in &graph.adj_list[node] {
+            let edge = &graph.edges[id];
+            let to = edge.to(node);
+            let next = d + edge.weight();
+
+            if dist[to].is_none_or(|cur| next < cur) {
+                dist[to] = Some(next);
+                parent[to] = Some(node);
+                heap.push(Reverse((next, to)));
+            }
+        }
+    }
+
+    (dist, parent)
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -408,5 +1023,308 @@ mod tests {
 
         assert_eq!(edges, cycle_edges);
     }
+
+    #[test]
+    fn test_eulerian_path_large_chain_does_not_overflow_stack() {
+        // A long simple chain 0-1-2-...-n, which used to recurse once per edge.
+        const N: usize = 200_000;
+
+        let edges: Vec<(usize, usize)> = (0..N).map(|i| (i, i + 1)).collect();
+        let graph = Graph::<(), (usize, usize)>::from_edges(N + 1, edges, |x| x, true);
+
+        let path = find_eulerian_path(&graph).unwrap();
+
+        assert_eq!(path.len(), N + 1);
+        assert_eq!(path.first(), Some(&0));
+        assert_eq!(path.last(), Some(&N));
+    }
+
+    #[test]
+    fn test_eulerian_directed_path_large_chain_does_not_overflow_stack() {
+        // A long directed chain 0->1->2->...->n, which exercises the same
+        // current-arc stack as the undirected case but through the directed
+        // (non-BidirectionalEdge) `usize` edge type and its `reverse()` step.
+        const N: usize = 200_000;
+
+        let edges: Vec<(usize, usize)> = (0..N).map(|i| (i, i + 1)).collect();
+        let graph = Graph::<(), usize>::from_edges(N + 1, edges, |x| x.1, false);
+
+        let path = find_eulerian_path(&graph).unwrap();
+
+        assert_eq!(path.len(), N + 1);
+        assert_eq!(path.first(), Some(&0));
+        assert_eq!(path.last(), Some(&N));
+    }
+
+    #[test]
+    fn test_max_flow_simple() {
+        let mut graph = Graph::<(), FlowEdge>::with_capacity(4, 5, false);
+
+        graph.push_flow_edge(0, 1, 3);
+        graph.push_flow_edge(0, 2, 2);
+        graph.push_flow_edge(1, 2, 1);
+        graph.push_flow_edge(1, 3, 2);
+        graph.push_flow_edge(2, 3, 3);
+
+        assert_eq!(max_flow(&mut graph, 0, 3), 5);
+    }
+
+    #[test]
+    fn test_max_flow_bipartite_matching() {
+        // Bipartite matching between {0, 1, 2} and {3, 4}, wired through a
+        // source (5) and a sink (6).
+        let mut graph = Graph::<(), FlowEdge>::with_capacity(7, 0, false);
+
+        graph.push_flow_edge(5, 0, 1);
+        graph.push_flow_edge(5, 1, 1);
+        graph.push_flow_edge(5, 2, 1);
+        graph.push_flow_edge(3, 6, 1);
+        graph.push_flow_edge(4, 6, 1);
+
+        graph.push_flow_edge(0, 3, 1);
+        graph.push_flow_edge(1, 3, 1);
+        graph.push_flow_edge(1, 4, 1);
+        graph.push_flow_edge(2, 4, 1);
+
+        assert_eq!(max_flow(&mut graph, 5, 6), 2);
+    }
+
+    #[test]
+    fn test_max_flow_no_path() {
+        let mut graph = Graph::<(), FlowEdge>::with_capacity(2, 0, false);
+
+        assert_eq!(max_flow(&mut graph, 0, 1), 0);
+    }
+
+    #[test]
+    fn test_max_flow_limited_stops_at_the_cap() {
+        let mut graph = Graph::<(), FlowEdge>::with_capacity(4, 5, false);
+
+        graph.push_flow_edge(0, 1, 3);
+        graph.push_flow_edge(0, 2, 2);
+        graph.push_flow_edge(1, 2, 1);
+        graph.push_flow_edge(1, 3, 2);
+        graph.push_flow_edge(2, 3, 3);
+
+        assert_eq!(max_flow_limited(&mut graph, 0, 3, 2), 2);
+    }
+
+    #[test]
+    fn test_min_cost_max_flow_picks_cheapest_paths() {
+        // Two parallel paths from 0 to 3, one cheap and narrow, one expensive and wide.
+        let mut graph = Graph::<(), FlowEdge>::with_capacity(4, 0, false);
+
+        graph.push_cost_flow_edge(0, 1, 1, 1);
+        graph.push_cost_flow_edge(1, 3, 1, 1);
+        graph.push_cost_flow_edge(0, 2, 5, 10);
+        graph.push_cost_flow_edge(2, 3, 5, 10);
+
+        let (flow, cost) = min_cost_max_flow(&mut graph, 0, 3);
+
+        assert_eq!(flow, 6);
+        assert_eq!(cost, 2 + 5 * 20);
+    }
+
+    #[test]
+    fn test_min_cost_flow_handles_negative_edges() {
+        // A reward arc (negative cost) that is only reachable through a
+        // positive-cost arc first, which Bellman-Ford potentials must absorb.
+        let mut graph = Graph::<(), FlowEdge>::with_capacity(3, 0, false);
+
+        graph.push_cost_flow_edge(0, 1, 2, 5);
+        graph.push_cost_flow_edge(1, 2, 2, -3);
+
+        let (flow, cost) = min_cost_max_flow(&mut graph, 0, 2);
+
+        assert_eq!(flow, 2);
+        assert_eq!(cost, 2 * (5 - 3));
+    }
+
+    #[test]
+    fn test_min_cost_flow_limited() {
+        let mut graph = Graph::<(), FlowEdge>::with_capacity(2, 0, false);
+
+        graph.push_cost_flow_edge(0, 1, 10, 2);
+
+        let (flow, cost) = min_cost_flow_limited(&mut graph, 0, 1, 4);
+
+        assert_eq!(flow, 4);
+        assert_eq!(cost, 8);
+    }
+
+    #[test]
+    fn test_bridges_on_a_path() {
+        // 0 - 1 - 2 - 3, every edge is a bridge.
+        let edges: Vec<(usize, usize)> = vec![(0, 1), (1, 2), (2, 3)];
+        let graph = Graph::<(), (usize, usize)>::from_edges(4, edges, |x| x, true);
+
+        let mut bridges = find_bridges(&graph);
+        bridges.sort();
+
+        assert_eq!(bridges, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_bridges_and_articulation_with_a_cycle() {
+        // A triangle (0, 1, 2) hanging off of node 2 via a bridge to node 3.
+        let edges: Vec<(usize, usize)> = vec![(0, 1), (1, 2), (2, 0), (2, 3)];
+        let graph = Graph::<(), (usize, usize)>::from_edges(4, edges, |x| x, true);
+
+        assert_eq!(find_bridges(&graph), vec![3]);
+
+        let mut articulation = find_articulation_points(&graph);
+        articulation.sort();
+        assert_eq!(articulation, vec![2]);
+    }
+
+    #[test]
+    fn test_biconnected_components_partition_the_edges() {
+        let edges: Vec<(usize, usize)> = vec![(0, 1), (1, 2), (2, 0), (2, 3)];
+        let graph = Graph::<(), (usize, usize)>::from_edges(4, edges, |x| x, true);
+
+        let mut components: Vec<Vec<usize>> = biconnected_components(&graph)
+            .into_iter()
+            .map(|mut c| { c.sort(); c })
+            .collect();
+        components.sort();
+
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_no_bridges_in_a_simple_cycle() {
+        let edges: Vec<(usize, usize)> = vec![(0, 1), (1, 2), (2, 3), (3, 0)];
+        let graph = Graph::<(), (usize, usize)>::from_edges(4, edges, |x| x, true);
+
+        assert_eq!(find_bridges(&graph), Vec::<usize>::new());
+        assert_eq!(find_articulation_points(&graph), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_bridges_on_a_long_chain_does_not_overflow_stack() {
+        // A long simple chain 0-1-2-...-n, every edge of which is a bridge.
+        const N: usize = 200_000;
+
+        let edges: Vec<(usize, usize)> = (0..N).map(|i| (i, i + 1)).collect();
+        let graph = Graph::<(), (usize, usize)>::from_edges(N + 1, edges, |x| x, true);
+
+        assert_eq!(find_bridges(&graph).len(), N);
+        assert_eq!(find_articulation_points(&graph).len(), N - 1);
+    }
+
+    #[test]
+    fn test_read_edge_list_zero_indexed() {
+        use crate::io::InParser;
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"0 1\n1 2\n2 0");
+        let mut parser = InParser::new(BufReader::new(reader));
+
+        let graph = Graph::<(), (usize, usize)>::read_edge_list(
+            &mut parser, 3, 3, true, false, |x| x
+        );
+
+        assert_eq!(graph.v(), 3);
+        assert_eq!(graph.e(), 3);
+        assert_eq!(find_bridges(&graph), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_read_edge_list_one_indexed() {
+        use crate::io::InParser;
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"1 2\n2 3");
+        let mut parser = InParser::new(BufReader::new(reader));
+
+        let graph = Graph::<(), (usize, usize)>::read_edge_list(
+            &mut parser, 3, 2, true, true, |x| x
+        );
+
+        assert_eq!(graph.edges, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_read_adjacency_matrix_undirected() {
+        use crate::io::InParser;
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"0 1 0\n1 0 1\n0 1 0");
+        let mut parser = InParser::new(BufReader::new(reader));
+
+        let graph = Graph::<(), (usize, usize)>::read_adjacency_matrix(
+            &mut parser, 3, true, |x| x
+        );
+
+        assert_eq!(graph.edges, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_read_adjacency_matrix_directed() {
+        use crate::io::InParser;
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"0 1 0\n0 0 1\n0 0 0");
+        let mut parser = InParser::new(BufReader::new(reader));
+
+        let graph = Graph::<(), (usize, usize)>::read_adjacency_matrix(
+            &mut parser, 3, false, |x| x
+        );
+
+        assert_eq!(graph.edges, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_edge_list_round_trips_through_write_and_read() {
+        use crate::io::{InParser, OutParser};
+        use std::io::{BufReader, Cursor};
+
+        let edges: Vec<(usize, usize)> = vec![(0, 1), (1, 2), (2, 0)];
+        let graph = Graph::<(), (usize, usize)>::from_edges(3, edges.clone(), |x| x, true);
+
+        let mut bytes = Vec::<u8>::new();
+        OutParser::new(&mut bytes).write_edge_list(&graph);
+
+        let mut parser = InParser::new(BufReader::new(Cursor::new(bytes)));
+        let v: usize = parser.read();
+        let e: usize = parser.read();
+        let round_tripped = Graph::<(), (usize, usize)>::read_edge_list(
+            &mut parser, v, e, true, false, |x| x
+        );
+
+        assert_eq!(round_tripped.edges, edges);
+    }
+
+    #[test]
+    fn test_dijkstra_shortest_paths() {
+        // 0 -> 1 (4), 0 -> 2 (1), 2 -> 1 (1), 1 -> 3 (1), 2 -> 3 (5)
+        let edges: Vec<(usize, usize, i64)> = vec![
+            (0, 1, 4),
+            (0, 2, 1),
+            (2, 1, 1),
+            (1, 3, 1),
+            (2, 3, 5),
+        ];
+
+        let graph = Graph::<(), (usize, usize, i64)>::from_edges(4, edges, |x| x, false);
+
+        let (dist, parent) = dijkstra(&graph, 0);
+
+        assert_eq!(dist, vec![Some(0), Some(2), Some(1), Some(3)]);
+        assert_eq!(parent[1], Some(2));
+        assert_eq!(parent[2], Some(0));
+        assert_eq!(parent[3], Some(1));
+    }
+
+    #[test]
+    fn test_dijkstra_unreachable_node() {
+        let edges: Vec<(usize, i64)> = vec![(1, 1)];
+        let mut graph = Graph::<(), (usize, i64)>::with_capacity(3, 0, false);
+        graph.push_directed_edge(0, edges[0]);
+
+        let (dist, _) = dijkstra(&graph, 0);
+
+        assert_eq!(dist, vec![Some(0), Some(1), None]);
+    }
 }
 
@@ -18,7 +18,7 @@
 
 #![allow(dead_code)]
 
-use std::io::{Read, BufRead, Stdin, BufReader, Write, BufWriter, Stdout};
+use std::io::{Read, Stdin, Write, BufWriter, Stdout};
 use std::fs::File;
 use std::str::FromStr;
 use std::fmt::Debug;
@@ -42,11 +42,17 @@ use std::fmt::Debug;
 /// assert_eq!(val, "asdf");
 /// ```
 pub struct InParser<T: Read> {
-    reader: BufReader<T>,
+    reader: T,
     buffer: Vec<u8>,
-    cursor: usize
+    filled: usize,
+    cursor: usize,
+    eof_flag: bool,
 }
 
+/// Default buffer capacity for [InParser::new], chosen to keep refills infrequent on large inputs
+/// without using so much memory it matters.
+const DEFAULT_BUFFER_CAPACITY: usize = 1 << 16;
+
 impl InParser<Stdin> {
     /// Create a parser from stdin.
     pub fn from_stdin() -> InParser<Stdin> {
@@ -63,43 +69,77 @@ impl InParser<File> {
 }
 
 impl<T: Read> InParser<T> {
-    /// Create a parser from any object that implements [Read].
+    /// Create a parser from any object that implements [Read], with a default buffer capacity of
+    /// 64 KiB.
     pub fn new(reader: T) -> InParser<T> {
-        let mut reader = BufReader::new(reader);
+        InParser::with_capacity(reader, DEFAULT_BUFFER_CAPACITY)
+    }
 
-        let buffer = reader.fill_buf()
-            .expect("Failed to fill buffer")
-            .to_vec();
-        
-        InParser {
+    /// Create a parser from any object that implements [Read], reading into a reusable buffer of
+    /// `cap` bytes instead of the default capacity.
+    ///
+    /// Mirrors `BufReader::with_capacity`: a bigger buffer means fewer, larger reads on big
+    /// inputs, at the cost of holding `cap` bytes for the lifetime of the parser. The buffer is
+    /// read into in place on every refill, rather than being reallocated, so
+    /// [advance_cursor](InParser::advance_cursor) never allocates past construction.
+    pub fn with_capacity(reader: T, cap: usize) -> InParser<T> {
+        let mut parser = InParser {
             reader,
-            buffer,
+            buffer: vec![0; cap],
+            filled: 0,
             cursor: 0,
-        }
+            eof_flag: false,
+        };
+
+        parser.refill();
+        parser
+    }
+
+    /// Refill the buffer in place from the underlying reader, resetting the cursor to its start.
+    fn refill(&mut self) {
+        self.filled = self.reader.read(&mut self.buffer)
+            .expect("Failed to fill buffer");
+        self.eof_flag = self.filled == 0;
+        self.cursor = 0;
     }
 
     /// Returns the byte at the current position of the cursor or [None] if the
     /// entire input has been consumed.
     pub fn get_current_byte(&mut self) -> Option<u8> {
-        if self.cursor < self.buffer.len() {
-            return Some(self.buffer[self.cursor]); 
+        if self.cursor < self.filled {
+            return Some(self.buffer[self.cursor]);
         }
         return None
     }
 
     /// Advance the cursor to the next position.
     pub fn advance_cursor(&mut self) {
-        self.cursor += 1;
-        if self.cursor >= self.buffer.len() {
-            self.reader.consume(self.buffer.len());
-            self.buffer = self.reader.fill_buf()
-                .expect("Failed to fill buffer")
-                .to_vec();
+        if self.eof_flag {
+            return;
+        }
 
-            self.cursor = 0;
+        self.cursor += 1;
+        if self.cursor >= self.filled {
+            self.refill();
         }
     }
 
+    /// Returns `true` if another token remains, skipping any whitespace in front of it; `false`
+    /// once only whitespace or nothing is left.
+    pub fn has_next(&mut self) -> bool {
+        self.skip_spaces();
+        self.get_current_byte().is_some()
+    }
+
+    /// Returns `true` if there is not a single byte left to read, without skipping whitespace.
+    ///
+    /// Unlike [has_next](InParser::has_next), this does not treat trailing whitespace as "no
+    /// more input" — it only reports the raw end of the stream, which is what [read_line] and
+    /// [read_char] need to distinguish "nothing left" from "next byte is a separator".
+    pub fn at_eof(&mut self) -> bool {
+        self.get_current_byte().is_none()
+    }
+
     fn skip_spaces(&mut self) {
         while self.get_current_byte() == Some(b' ') ||
               self.get_current_byte() == Some(b'\n') {
@@ -134,17 +174,320 @@ impl<T: Read> InParser<T> {
         }
     }
     
+    /// Read the next element from the input, or [None] at end of input.
+    ///
+    /// Unlike [read](InParser::read), this does not panic when the input is exhausted, which is
+    /// what lets "read until EOF" loops be written as `while let Some(x) = p.try_read()`.
+    pub fn try_read<F: FromStr>(&mut self) -> Option<F>
+    where <F as FromStr>::Err: Debug {
+        self.get_token().map(|token| token.parse::<F>().unwrap())
+    }
+
+    /// Read the next whitespace-delimited token as a [String], or [None] at end of input.
+    ///
+    /// A public, explicitly-named entry point for the same "stop instead of panicking at EOF"
+    /// behaviour as [try_read](InParser::try_read), for callers who want a string specifically
+    /// without naming the type parameter.
+    pub fn try_read_string(&mut self) -> Option<String> {
+        self.get_token()
+    }
+
+    /// Read the next element from the input, or [None] at end of input. A name-matched alias of
+    /// [try_read](InParser::try_read) for callers looking specifically for a numeric read.
+    pub fn try_read_number<F: FromStr>(&mut self) -> Option<F>
+    where <F as FromStr>::Err: Debug {
+        self.try_read()
+    }
+
+    /// Returns the byte at the current position of the cursor, without skipping whitespace or
+    /// advancing the cursor, or [None] at end of input. A name-matched alias of
+    /// [get_current_byte](InParser::get_current_byte).
+    pub fn peek_byte(&mut self) -> Option<u8> {
+        self.get_current_byte()
+    }
+
     /// Read the next element from the input.
     pub fn read<F: FromStr>(&mut self) -> F
     where <F as FromStr>::Err: Debug{
-        let token = self.get_token()
-            .expect("Tried to read from empty token");
+        self.try_read().expect("Tried to read from empty token")
+    }
+
+    /// Returns an iterator that keeps calling [try_read](InParser::try_read) until end of input.
+    pub fn read_iter<F: FromStr>(&mut self) -> impl Iterator<Item = F> + '_
+    where <F as FromStr>::Err: Debug {
+        std::iter::from_fn(move || self.try_read())
+    }
+
+    /// Read a floating-point number directly off the byte buffer, without allocating an
+    /// intermediate string.
+    ///
+    /// Parses an optional sign, integer digits, an optional `.` fractional part, and an optional
+    /// `e`/`E` exponent (with its own optional sign) one byte at a time via
+    /// [get_current_byte](InParser::get_current_byte)/[advance_cursor](InParser::advance_cursor),
+    /// the same way [get_token](InParser::get_token) walks a token — useful for geometry and
+    /// probability inputs, where [read](InParser::read)'s [FromStr] path is the only option
+    /// otherwise. A leading `.` with no integer digits parses as the fraction alone (`.5` is
+    /// `0.5`), and a lone sign with no digits at all parses as a zero carrying that sign (`-` is
+    /// `-0.0`) rather than panicking.
+    pub fn read_float<F: From<f64>>(&mut self) -> F {
+        self.skip_spaces();
+
+        let sign = if self.get_current_byte() == Some(b'-') {
+            self.advance_cursor();
+            -1.0
+        } else if self.get_current_byte() == Some(b'+') {
+            self.advance_cursor();
+            1.0
+        } else {
+            1.0
+        };
+
+        let mut mantissa = 0.0_f64;
+        while let Some(byte) = self.get_current_byte() {
+            if !byte.is_ascii_digit() {
+                break;
+            }
+            mantissa = mantissa * 10.0 + (byte - b'0') as f64;
+            self.advance_cursor();
+        }
+
+        if self.get_current_byte() == Some(b'.') {
+            self.advance_cursor();
 
-        token.parse::<F>()
-            .unwrap()
+            let mut scale = 0.1_f64;
+            while let Some(byte) = self.get_current_byte() {
+                if !byte.is_ascii_digit() {
+                    break;
+                }
+                mantissa += (byte - b'0') as f64 * scale;
+                scale *= 0.1;
+                self.advance_cursor();
+            }
+        }
+
+        let mut exponent = 0_i32;
+        if matches!(self.get_current_byte(), Some(b'e') | Some(b'E')) {
+            self.advance_cursor();
+
+            let exp_sign = if self.get_current_byte() == Some(b'-') {
+                self.advance_cursor();
+                -1
+            } else if self.get_current_byte() == Some(b'+') {
+                self.advance_cursor();
+                1
+            } else {
+                1
+            };
+
+            while let Some(byte) = self.get_current_byte() {
+                if !byte.is_ascii_digit() {
+                    break;
+                }
+                exponent = exponent * 10 + (byte - b'0') as i32;
+                self.advance_cursor();
+            }
+
+            exponent *= exp_sign;
+        }
+
+        F::from(sign * mantissa * 10f64.powi(exponent))
+    }
+
+    /// Read everything up to and including the next `\n`, without skipping leading whitespace, or
+    /// [None] at end of input.
+    ///
+    /// Useful for whitespace-significant input (e.g. a grid row) where [read](InParser::read)'s
+    /// space/newline skipping would lose information.
+    pub fn read_line(&mut self) -> Option<String> {
+        if self.at_eof() {
+            return None;
+        }
+
+        let mut line = Vec::new();
+
+        while let Some(byte) = self.get_current_byte() {
+            self.advance_cursor();
+            line.push(byte);
+
+            if byte == b'\n' {
+                break;
+            }
+        }
+
+        Some(String::from_utf8(line)
+            .expect("Failed to convert into valid utf8"))
+    }
+
+    /// Read the next raw byte without skipping whitespace, or [None] at end of input.
+    ///
+    /// To skip leading separators first (e.g. when a grid is preceded by a count on its own
+    /// line), call [has_next](InParser::has_next), which skips whitespace as a side effect.
+    pub fn read_char(&mut self) -> Option<u8> {
+        let byte = self.get_current_byte();
+
+        if byte.is_some() {
+            self.advance_cursor();
+        }
+
+        byte
+    }
+
+    /// Read `n` elements, one [read](InParser::read) at a time.
+    ///
+    /// This is the common "read n, then read n typed rows" shape; see also the [scan] macro.
+    pub fn read_vec<F: FromStr>(&mut self, n: usize) -> Vec<F>
+    where <F as FromStr>::Err: Debug {
+        (0..n).map(|_| self.read()).collect()
+    }
+
+    /// Read `n` numbers. A thin wrapper over [read_vec](InParser::read_vec) for now, kept as its
+    /// own name so callers have a stable spot to move to once `InParser` grows a dedicated
+    /// fast-path integer parser.
+    pub fn read_number_vec<F: FromStr>(&mut self, n: usize) -> Vec<F>
+    where <F as FromStr>::Err: Debug {
+        self.read_vec(n)
+    }
+
+    /// Read a fixed-size tuple, one [read](InParser::read) per field, via [ReadTuple].
+    pub fn read_tuple<Tup: ReadTuple>(&mut self) -> Tup {
+        Tup::read_from(self)
+    }
+
+    /// Read a pair of values, one [read](InParser::read) per field.
+    pub fn read_pair<A: FromStr, B: FromStr>(&mut self) -> (A, B)
+    where <A as FromStr>::Err: Debug,
+          <B as FromStr>::Err: Debug {
+        self.read_tuple()
     }
 }
 
+/// Fixed-size tuples of [FromStr] fields that [InParser::read_tuple] can read one field at a time.
+pub trait ReadTuple: Sized {
+    /// Read one field per tuple element, in order.
+    fn read_from<R: Read>(parser: &mut InParser<R>) -> Self;
+}
+
+macro_rules! impl_read_tuple {
+    ($($t:ident),+) => {
+        impl<$($t: FromStr),+> ReadTuple for ($($t,)+)
+        where $(<$t as FromStr>::Err: Debug),+ {
+            fn read_from<R: Read>(parser: &mut InParser<R>) -> Self {
+                ($(parser.read::<$t>(),)+)
+            }
+        }
+    };
+}
+
+impl_read_tuple!(A, B);
+impl_read_tuple!(A, B, C);
+impl_read_tuple!(A, B, C, D);
+
+/// Read one or more typed values from an [InParser] in a single statement.
+///
+/// `scan!(parser, Type)` reads a single value. `scan!(parser, TypeA, TypeB, ...)` reads a tuple,
+/// one [read](InParser::read) per type. Appending `; n` repeats the read `n` times and collects
+/// the results into a `Vec`, matching the common "read n, then read n typed rows" competitive
+/// input shape.
+///
+/// ```
+/// # use competitive_rust::scan;
+/// # use competitive_rust::io::InParser;
+/// # use std::io::{Cursor, BufReader};
+/// let mut parser = InParser::new(BufReader::new(Cursor::new(b"3\n1 2\n3 4\n5 6")));
+///
+/// let n: usize = scan!(parser, usize);
+/// let rows: Vec<(i32, i32)> = scan!(parser, i32, i32; n);
+///
+/// assert_eq!(rows, vec![(1, 2), (3, 4), (5, 6)]);
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($parser:expr, $t:ty; $n:expr) => {
+        (0..$n).map(|_| $parser.read::<$t>()).collect::<Vec<_>>()
+    };
+    ($parser:expr, $($t:ty),+; $n:expr) => {
+        (0..$n).map(|_| ($( $parser.read::<$t>() ),+)).collect::<Vec<_>>()
+    };
+    ($parser:expr, $($t:ty),+) => {
+        ($( $parser.read::<$t>() ),+)
+    };
+}
+
+/// Declare and read one or more typed values from an [InParser] in a single block, expanding
+/// `n: usize, a: [i64; n]` into a sequence of [reads](InParser::read).
+///
+/// Beyond a plain `$t:ty`, the following type forms are supported: `[$t; $len]` collects `$len`
+/// reads into a `Vec`, `($t, $t, ...)` reads a tuple, `chars`/`bytes` read a token as a
+/// `Vec<char>`/`Vec<u8>`, and `usize1` reads a `usize` and subtracts one, for 1-indexed input.
+///
+/// Pass `parser = $p` to reuse an existing parser; otherwise one is built from stdin.
+///
+/// ```
+/// # use competitive_rust::input;
+/// # use competitive_rust::io::InParser;
+/// # use std::io::{Cursor, BufReader};
+/// let mut parser = InParser::new(BufReader::new(Cursor::new(b"3\n1 2\n3 4\n5 6\nhi")));
+///
+/// input! {
+///     parser = parser,
+///     n: usize,
+///     edges: [(usize, usize); n],
+///     s: chars,
+/// }
+///
+/// assert_eq!(n, 3);
+/// assert_eq!(edges, vec![(1, 2), (3, 4), (5, 6)]);
+/// assert_eq!(s, vec!['h', 'i']);
+/// ```
+#[macro_export]
+macro_rules! input {
+    (parser = $p:expr, $($rest:tt)*) => {
+        $crate::input_inner!($p; $($rest)*);
+    };
+    ($($rest:tt)*) => {
+        let mut __input_parser = $crate::io::InParser::from_stdin();
+        $crate::input_inner!(__input_parser; $($rest)*);
+    };
+}
+
+/// Implementation detail of [input!]: walks the `name: type` declarations one at a time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! input_inner {
+    ($p:expr; ) => {};
+    ($p:expr; $name:ident : $t:tt) => {
+        let $name = $crate::read_value!($p; $t);
+    };
+    ($p:expr; $name:ident : $t:tt, $($rest:tt)*) => {
+        let $name = $crate::read_value!($p; $t);
+        $crate::input_inner!($p; $($rest)*);
+    };
+}
+
+/// Implementation detail of [input!]: reads a single declared type form from an [InParser].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! read_value {
+    ($p:expr; usize1) => {
+        $p.read::<usize>() - 1
+    };
+    ($p:expr; chars) => {
+        $p.read::<String>().chars().collect::<Vec<char>>()
+    };
+    ($p:expr; bytes) => {
+        $p.read::<String>().into_bytes()
+    };
+    ($p:expr; [$t:tt; $len:expr]) => {
+        (0..$len).map(|_| $crate::read_value!($p; $t)).collect::<Vec<_>>()
+    };
+    ($p:expr; ($($t:tt),+)) => {
+        ($($crate::read_value!($p; $t)),+)
+    };
+    ($p:expr; $t:tt) => {
+        $p.read::<$t>()
+    };
+}
+
 /// Writer used for writing in stdout, a file, or any other place.
 /// 
 /// ```no_run
@@ -180,9 +523,64 @@ impl<T: Write> OutParser<T> {
     pub fn write<F: ToString>(&mut self, val: F) -> &mut Self {
         self.writer.write(&val.to_string().as_bytes())
             .expect("Failed to write");
-        
+
+        self
+    }
+
+    /// Write a graph as an edge list: the node and edge counts on the first line, followed by one
+    /// `u v` pair per line, one per edge. The inverse of
+    /// [Graph::read_edge_list](crate::graph::Graph::read_edge_list).
+    pub fn write_edge_list<V, E>(&mut self, graph: &crate::graph::Graph<V, E>) -> &mut Self
+    where V: Default,
+          E: crate::graph::BidirectionalEdge {
+        self.write(format!("{} {}\n", graph.v(), graph.e()));
+
+        for edge in &graph.edges {
+            let (a, b) = edge.as_pair();
+            self.write(format!("{} {}\n", a, b));
+        }
+
+        self
+    }
+
+    /// Write a value to the target, followed by a newline.
+    pub fn writeln<F: ToString>(&mut self, val: F) -> &mut Self {
+        self.write(val);
+        self.write("\n")
+    }
+
+    /// Write every item of `iter`, joined by `sep`, with no trailing separator. The common "print
+    /// a vector on one line" output pattern.
+    pub fn write_all<I>(&mut self, iter: I, sep: &str) -> &mut Self
+    where I: IntoIterator,
+          I::Item: ToString {
+        let mut first = true;
+
+        for item in iter {
+            if !first {
+                self.write(sep);
+            }
+            first = false;
+
+            self.write(item);
+        }
+
         self
     }
+
+    /// Flush the underlying buffered writer, so everything written so far reaches the target
+    /// instead of sitting in the buffer.
+    pub fn flush(&mut self) {
+        self.writer.flush().expect("Failed to flush");
+    }
+}
+
+impl<T: Write> Drop for OutParser<T> {
+    /// Flush on drop, so the last line isn't lost if `main` returns before an explicit
+    /// [flush](OutParser::flush) call.
+    fn drop(&mut self) {
+        let _ = self.writer.flush();
+    }
 }
 
 impl OutParser<Stdout> {
@@ -204,6 +602,21 @@ impl OutParser<File> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn with_capacity_reads_across_many_refills() {
+        use std::io::Cursor;
+
+        // A tiny capacity forces several refills mid-token and mid-whitespace-run, exercising the
+        // in-place buffer reuse rather than just the single-refill default-capacity path.
+        let values: Vec<i64> = (0..500).collect();
+        let input = values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" ");
+
+        let mut reader = InParser::with_capacity(Cursor::new(input.as_bytes()), 4);
+
+        let read_back: Vec<i64> = reader.read_iter().collect();
+        assert_eq!(read_back, values);
+    }
+
     #[test]
     fn read_normal_int() {
         use std::io::{Cursor, BufReader};
@@ -256,6 +669,226 @@ mod tests {
         assert_eq!(reader.read::<i32>(), 2);
     }
 
+    #[test]
+    fn try_read_returns_none_at_eof() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"1 2");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert_eq!(reader.try_read::<i32>(), Some(1));
+        assert_eq!(reader.try_read::<i32>(), Some(2));
+        assert_eq!(reader.try_read::<i32>(), None);
+        assert_eq!(reader.try_read::<i32>(), None);
+    }
+
+    #[test]
+    fn try_read_string_and_try_read_number_return_none_at_eof() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"asdf 1");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert_eq!(reader.try_read_string(), Some("asdf".to_string()));
+        assert_eq!(reader.try_read_number::<i32>(), Some(1));
+        assert_eq!(reader.try_read_number::<i32>(), None);
+        assert_eq!(reader.try_read_string(), None);
+    }
+
+    #[test]
+    fn peek_byte_does_not_advance_or_skip_whitespace() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b" a");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert_eq!(reader.peek_byte(), Some(b' '));
+        assert_eq!(reader.peek_byte(), Some(b' '));
+        assert_eq!(reader.read::<String>(), "a");
+        assert_eq!(reader.peek_byte(), None);
+    }
+
+    #[test]
+    fn read_float_parses_decimals_and_scientific_notation() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"12.5 -2.5 1e-2 -1.5E3 42");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert!((reader.read_float::<f64>() - 12.5).abs() < 1e-9);
+        assert!((reader.read_float::<f64>() - -2.5).abs() < 1e-9);
+        assert!((reader.read_float::<f64>() - 0.01).abs() < 1e-9);
+        assert!((reader.read_float::<f64>() - -1500.0).abs() < 1e-9);
+        assert!((reader.read_float::<f64>() - 42.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn read_float_handles_leading_dot_and_lone_sign() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b".5 -");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert!((reader.read_float::<f64>() - 0.5).abs() < 1e-9);
+        assert_eq!(reader.read_float::<f64>(), -0.0);
+    }
+
+    #[test]
+    fn has_next_reflects_remaining_input() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"  1  ");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert!(reader.has_next());
+        assert_eq!(reader.read::<i32>(), 1);
+        assert!(!reader.has_next());
+    }
+
+    #[test]
+    fn read_iter_collects_until_eof() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"1 2 3 4");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert_eq!(reader.read_iter::<i32>().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn read_line_keeps_interior_spaces() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"a b c\nd e f");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert_eq!(reader.read_line(), Some("a b c\n".to_string()));
+        assert_eq!(reader.read_line(), Some("d e f".to_string()));
+        assert_eq!(reader.read_line(), None);
+    }
+
+    #[test]
+    fn at_eof_tracks_raw_stream_end() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"1 ");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert!(!reader.at_eof());
+        assert_eq!(reader.read::<i32>(), 1);
+        // A trailing space remains: the raw stream isn't empty yet, even
+        // though there is no further token to read.
+        assert!(!reader.at_eof());
+        assert!(!reader.has_next());
+
+        reader.read_char();
+        assert!(reader.at_eof());
+    }
+
+    #[test]
+    fn read_char_does_not_skip_whitespace() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"a b");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert_eq!(reader.read_char(), Some(b'a'));
+        assert_eq!(reader.read_char(), Some(b' '));
+        assert_eq!(reader.read_char(), Some(b'b'));
+        assert_eq!(reader.read_char(), None);
+    }
+
+    #[test]
+    fn read_vec_collects_n_elements() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"1 2 3 4");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert_eq!(reader.read_vec::<i32>(3), vec![1, 2, 3]);
+        assert_eq!(reader.read::<i32>(), 4);
+    }
+
+    #[test]
+    fn read_number_vec_matches_read_vec() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"10 20 30");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert_eq!(reader.read_number_vec::<i64>(3), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn read_pair_and_read_tuple() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"1 2 3 asdf");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        assert_eq!(reader.read_pair::<i32, i32>(), (1, 2));
+        assert_eq!(reader.read_tuple::<(i32, String)>(), (3, "asdf".to_string()));
+    }
+
+    #[test]
+    fn scan_macro_reads_single_values_tuples_and_vecs() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"3\n1 2\n3 4\n5 6");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        let n: usize = scan!(reader, usize);
+        assert_eq!(n, 3);
+
+        let rows: Vec<(i32, i32)> = scan!(reader, i32, i32; n);
+        assert_eq!(rows, vec![(1, 2), (3, 4), (5, 6)]);
+    }
+
+    #[test]
+    fn scan_macro_reads_a_flat_vec() {
+        use std::io::{Cursor, BufReader};
+
+        let reader = Cursor::new(b"1 2 3 4");
+        let mut reader = InParser::new(BufReader::new(reader));
+
+        let values: Vec<i32> = scan!(reader, i32; 4);
+        assert_eq!(values, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn input_macro_reads_plain_values_and_arrays() {
+        use std::io::{Cursor, BufReader};
+
+        let mut parser = InParser::new(BufReader::new(Cursor::new(b"3\n1 2\n3 4\n5 6")));
+
+        input! {
+            parser = parser,
+            n: usize,
+            edges: [(usize, usize); n],
+        }
+
+        assert_eq!(n, 3);
+        assert_eq!(edges, vec![(1, 2), (3, 4), (5, 6)]);
+    }
+
+    #[test]
+    fn input_macro_reads_usize1_chars_and_bytes() {
+        use std::io::{Cursor, BufReader};
+
+        let mut parser = InParser::new(BufReader::new(Cursor::new(b"5 hi hi")));
+
+        input! {
+            parser = parser,
+            i: usize1,
+            s: chars,
+            b: bytes,
+        }
+
+        assert_eq!(i, 4);
+        assert_eq!(s, vec!['h', 'i']);
+        assert_eq!(b, b"hi".to_vec());
+    }
+
     #[test]
     fn write_simple() {
         let mut bytes = Vec::<u8>::new();
@@ -303,6 +936,61 @@ mod tests {
 
         assert_eq!(&mut bytes, b"3.1415926536");
     }
+
+    #[test]
+    fn writeln_appends_a_newline() {
+        let mut bytes = Vec::<u8>::new();
+
+        {
+            let mut writer = OutParser::new(&mut bytes);
+
+            writer.write("x: ").writeln(1).write("y: ").writeln(2);
+        }
+
+        assert_eq!(&mut bytes, b"x: 1\ny: 2\n");
+    }
+
+    #[test]
+    fn write_all_joins_with_a_separator() {
+        let mut bytes = Vec::<u8>::new();
+
+        {
+            let mut writer = OutParser::new(&mut bytes);
+
+            writer.write_all(vec![1, 2, 3], " ").write("\n");
+        }
+
+        assert_eq!(&mut bytes, b"1 2 3\n");
+    }
+
+    #[test]
+    fn dropping_the_writer_flushes_the_last_line() {
+        let mut bytes = Vec::<u8>::new();
+
+        {
+            let mut writer = OutParser::new(&mut bytes);
+            writer.write("no explicit flush");
+        }
+
+        assert_eq!(&mut bytes, b"no explicit flush");
+    }
+
+    #[test]
+    fn write_edge_list_writes_counts_then_pairs() {
+        use crate::graph::Graph;
+
+        let edges: Vec<(usize, usize)> = vec![(0, 1), (1, 2)];
+        let graph = Graph::<(), (usize, usize)>::from_edges(3, edges, |x| x, true);
+
+        let mut bytes = Vec::<u8>::new();
+
+        {
+            let mut writer = OutParser::new(&mut bytes);
+            writer.write_edge_list(&graph);
+        }
+
+        assert_eq!(std::str::from_utf8(&bytes).unwrap(), "3 2\n0 1\n1 2\n");
+    }
 }
 
 